@@ -1,15 +1,40 @@
 
 
+/// Which field of the clock the rotary encoder is currently editing.
+///
+/// `Run` means the encoder is idle and the clock just keeps ticking; pushing
+/// the encoder's switch cycles `Run -> Hours -> Minutes -> Seconds -> Run`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EditField {
+    Hours,
+    Minutes,
+    Seconds,
+    Run,
+}
+
+impl EditField {
+    /// Advances to the next field in the cycle.
+    pub fn next(self) -> Self {
+        match self {
+            EditField::Run => EditField::Hours,
+            EditField::Hours => EditField::Minutes,
+            EditField::Minutes => EditField::Seconds,
+            EditField::Seconds => EditField::Run,
+        }
+    }
+}
+
 /// Shared state for the clock
 pub struct ClockState {
     pub hours: u8,
     pub mins: u8,
     pub secs: u8,
+    pub edit_field: EditField,
 }
 
 impl ClockState {
     pub fn new(hours: u8, mins: u8, secs: u8) -> Self {
-        Self { hours, mins, secs }
+        Self { hours, mins, secs, edit_field: EditField::Run }
     }
 
     /// Increments the second. Returns true if minute also changed (display update needed).
@@ -29,4 +54,28 @@ impl ClockState {
             self.hours = (self.hours + 1) % 24;
         }
     }
+
+    /// Advances to the next editable field (pushed by the encoder switch).
+    pub fn cycle_edit_field(&mut self) {
+        self.edit_field = self.edit_field.next();
+    }
+
+    /// Applies `delta` detents from the rotary encoder to `field`, wrapping
+    /// within that field's valid range. `Run` is a no-op since nothing is
+    /// being edited.
+    pub fn adjust_field(&mut self, field: EditField, delta: i8) {
+        match field {
+            EditField::Hours => self.hours = wrapping_add(self.hours, delta, 24),
+            EditField::Minutes => self.mins = wrapping_add(self.mins, delta, 60),
+            EditField::Seconds => self.secs = wrapping_add(self.secs, delta, 60),
+            EditField::Run => {}
+        }
+    }
+}
+
+/// Adds `delta` to `value`, wrapping around `[0, modulus)`.
+fn wrapping_add(value: u8, delta: i8, modulus: u8) -> u8 {
+    let modulus = modulus as i16;
+    let result = (value as i16 + delta as i16).rem_euclid(modulus);
+    result as u8
 }
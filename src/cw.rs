@@ -0,0 +1,147 @@
+/// Morse code for each decimal digit, dot/dash only (ITU standard).
+pub const MORSE_DIGITS: [&str; 10] = [
+    "-----", // 0
+    ".----", // 1
+    "..---", // 2
+    "...--", // 3
+    "....-", // 4
+    ".....", // 5
+    "-....", // 6
+    "--...", // 7
+    "---..", // 8
+    "----.", // 9
+];
+
+/// Non-blocking Morse playback of a `HH MM` time group, advanced one
+/// timebase "unit" at a time via [`CwPlayer::tick`].
+///
+/// Unit lengths follow the usual Morse convention: a dot is 1 unit on, a
+/// dash is 3 units on, the gap between elements of the same symbol is 1
+/// unit off, the gap between digits is 3 units off, and the gap between
+/// the hour group and the minute group is 7 units off (word gap).
+pub struct CwPlayer {
+    digits: [u8; 4],
+    digit_idx: usize,
+    sym_idx: usize,
+    units_left: u8,
+    key_on: bool,
+    active: bool,
+}
+
+impl CwPlayer {
+    pub fn new() -> Self {
+        Self { digits: [0; 4], digit_idx: 0, sym_idx: 0, units_left: 0, key_on: false, active: false }
+    }
+
+    /// Starts playback of `hours:mins` as four Morse digit groups.
+    pub fn start(&mut self, hours: u8, mins: u8) {
+        self.digits = [hours / 10, hours % 10, mins / 10, mins % 10];
+        self.digit_idx = 0;
+        self.sym_idx = 0;
+        self.key_on = true;
+        self.units_left = symbol_units(self.current_symbol());
+        self.active = true;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Advances playback by one timebase unit and returns whether the
+    /// buzzer should be keyed on for that unit.
+    pub fn tick(&mut self) -> bool {
+        if !self.active {
+            return false;
+        }
+
+        let out = self.key_on;
+        self.units_left -= 1;
+        if self.units_left == 0 {
+            self.advance();
+        }
+        out
+    }
+
+    fn current_symbol(&self) -> u8 {
+        MORSE_DIGITS[self.digits[self.digit_idx] as usize].as_bytes()[self.sym_idx]
+    }
+
+    fn advance(&mut self) {
+        if self.key_on {
+            // Just finished playing a dot/dash; move on to the gap after it.
+            self.key_on = false;
+            self.sym_idx += 1;
+            let code = MORSE_DIGITS[self.digits[self.digit_idx] as usize];
+            if self.sym_idx < code.len() {
+                self.units_left = 1; // inter-element gap
+                return;
+            }
+
+            // Finished this digit; move to the next one (or stop).
+            self.digit_idx += 1;
+            self.sym_idx = 0;
+            if self.digit_idx >= self.digits.len() {
+                self.active = false;
+                return;
+            }
+            self.units_left = if self.digit_idx == 2 { 7 } else { 3 }; // word gap between HH and MM
+        } else {
+            // Gap finished; key the next symbol.
+            self.key_on = true;
+            self.units_left = symbol_units(self.current_symbol());
+        }
+    }
+}
+
+fn symbol_units(symbol: u8) -> u8 {
+    if symbol == b'-' { 3 } else { 1 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbol_units_matches_dot_dash_timing() {
+        assert_eq!(symbol_units(b'.'), 1);
+        assert_eq!(symbol_units(b'-'), 3);
+    }
+
+    // Collapses consecutive equal ticks into (value, run length) pairs, so
+    // timing can be asserted against the documented unit counts rather than
+    // one `tick()` call at a time.
+    fn run_lengths(ticks: &[bool]) -> std::vec::Vec<(bool, usize)> {
+        let mut runs: std::vec::Vec<(bool, usize)> = std::vec::Vec::new();
+        for &tick in ticks {
+            match runs.last_mut() {
+                Some((value, count)) if *value == tick => *count += 1,
+                _ => runs.push((tick, 1)),
+            }
+        }
+        runs
+    }
+
+    #[test]
+    fn plays_hours_then_a_word_gap_before_minutes() {
+        // Hours 00, minutes 00: every digit is "-----" (5 dashes), so this
+        // exercises dot/dash (n/a here), inter-element, inter-character and
+        // the hour/minute word gap in one pass.
+        let mut player = CwPlayer::new();
+        player.start(0, 0);
+
+        // 2 digits * (5 dashes * 3 units + 4 inter-element gaps * 1 unit)
+        // + 1 inter-character gap (3 units) + enough ticks to see the word
+        // gap (7 units) and the first dash of the minutes group.
+        let ticks: std::vec::Vec<bool> = (0..19 + 3 + 19 + 7 + 1).map(|_| player.tick()).collect();
+
+        let mut expected = std::vec::Vec::new();
+        let digit = [(true, 3), (false, 1), (true, 3), (false, 1), (true, 3), (false, 1), (true, 3), (false, 1), (true, 3)];
+        expected.extend_from_slice(&digit); // hours tens digit
+        expected.push((false, 3)); // inter-character gap, within the hours group
+        expected.extend_from_slice(&digit); // hours units digit
+        expected.push((false, 7)); // word gap between hours and minutes
+        expected.push((true, 1)); // first unit of the minutes group's first dash
+
+        assert_eq!(run_lengths(&ticks), expected);
+    }
+}
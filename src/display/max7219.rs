@@ -0,0 +1,258 @@
+use crate::clock::ClockState;
+use crate::display::Display;
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point, Size},
+    mono_font::{ascii::FONT_4X6, MonoFont, MonoTextStyle},
+    pixelcolor::BinaryColor,
+    text::{Baseline, Text},
+    Drawable, Pixel,
+};
+use max7219::{connectors::Connector, MAX7219};
+
+pub const MATRIX_WIDTH: u32 = 32;
+pub const MATRIX_HEIGHT: u32 = 8;
+
+const FONT: MonoFont = FONT_4X6;
+
+/// A 32x8 bit framebuffer for the four chained MAX7219 (FC16 wiring)
+/// devices, addressable as an `embedded-graphics` [`DrawTarget`].
+///
+/// Column 0 is the leftmost pixel and is stored as the MSB of `rows[y]`,
+/// matching the bit order the old hand-rolled `prepare_buffer` used to
+/// split each row across the four devices.
+pub struct Framebuffer {
+    rows: [u32; MATRIX_HEIGHT as usize],
+}
+
+impl Framebuffer {
+    pub fn new() -> Self {
+        Self { rows: [0; MATRIX_HEIGHT as usize] }
+    }
+
+    /// Splits the framebuffer into the 8-row buffers for each of the 4
+    /// chained devices (FC16 layout), ready for `MAX7219::write_raw`.
+    pub fn device_buffers(&self) -> [[u8; MATRIX_HEIGHT as usize]; 4] {
+        let mut device_buffers = [[0u8; MATRIX_HEIGHT as usize]; 4];
+        for (dev_idx, buffer) in device_buffers.iter_mut().enumerate() {
+            let shift = 24 - dev_idx * 8;
+            for (row, value) in buffer.iter_mut().enumerate() {
+                *value = ((self.rows[row] >> shift) & 0xFF) as u8;
+            }
+        }
+        device_buffers
+    }
+}
+
+impl OriginDimensions for Framebuffer {
+    fn size(&self) -> Size {
+        Size::new(MATRIX_WIDTH, MATRIX_HEIGHT)
+    }
+}
+
+impl DrawTarget for Framebuffer {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if point.x < 0 || point.x >= MATRIX_WIDTH as i32 || point.y < 0 || point.y >= MATRIX_HEIGHT as i32 {
+                continue;
+            }
+            let bit = 1u32 << (MATRIX_WIDTH as i32 - 1 - point.x);
+            let row = &mut self.rows[point.y as usize];
+            match color {
+                BinaryColor::On => *row |= bit,
+                BinaryColor::Off => *row &= !bit,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Renders `HH:MM:SS` (8 glyphs at 4px wide, exactly the matrix width)
+/// into a fresh [`Framebuffer`].
+pub fn render_clock(clock: &ClockState) -> Framebuffer {
+    let digits = [
+        clock.hours / 10, clock.hours % 10,
+        10, // ':'
+        clock.mins / 10, clock.mins % 10,
+        10,
+        clock.secs / 10, clock.secs % 10,
+    ];
+    let mut bytes = [0u8; 8];
+    for (i, &d) in digits.iter().enumerate() {
+        bytes[i] = if d == 10 { b':' } else { b'0' + d };
+    }
+    let text = core::str::from_utf8(&bytes).unwrap();
+
+    let mut fb = Framebuffer::new();
+    let style = MonoTextStyle::new(&FONT, BinaryColor::On);
+    Text::with_baseline(text, Point::new(0, 1), style, Baseline::Top)
+        .draw(&mut fb)
+        .unwrap();
+    fb
+}
+
+/// Width (in pixels) of the off-screen canvas a [`Marquee`] renders into.
+pub const MARQUEE_MAX_WIDTH: u32 = 128;
+
+/// Maximum content width (in pixels) a [`Marquee`] can hold. The canvas
+/// needs one matrix-width of headroom for the window to start fully blank
+/// before the text *and* one more to end fully blank after it, so this is
+/// [`MARQUEE_MAX_WIDTH`] minus two matrix widths rather than one — e.g.
+/// `"HELLO"` at 4px/glyph comfortably fits.
+pub const MARQUEE_MAX_CONTENT_WIDTH: u32 = MARQUEE_MAX_WIDTH - 2 * MATRIX_WIDTH;
+
+/// A message rendered off-screen and scrolled across the 32px-wide matrix
+/// one column per call to [`Marquee::advance`], for content wider than the
+/// display (status text, a date line, ...).
+pub struct Marquee {
+    rows: [u128; MATRIX_HEIGHT as usize],
+    content_width: u32,
+    offset: u32,
+}
+
+impl Marquee {
+    /// Renders `text` into an off-screen buffer. Content beyond
+    /// [`MARQUEE_MAX_CONTENT_WIDTH`] is truncated, since the window can only
+    /// scroll across the fixed-size [`MARQUEE_MAX_WIDTH`] canvas.
+    pub fn new(text: &str) -> Self {
+        let mut canvas = WideCanvas { rows: [0; MATRIX_HEIGHT as usize] };
+        let style = MonoTextStyle::new(&FONT, BinaryColor::On);
+        // Start one matrix-width in, so the first window is blank.
+        Text::with_baseline(text, Point::new(MATRIX_WIDTH as i32, 1), style, Baseline::Top)
+            .draw(&mut canvas)
+            .unwrap();
+
+        let content_width = (text.chars().count() as u32 * FONT.character_size.width)
+            .min(MARQUEE_MAX_CONTENT_WIDTH);
+
+        // Blank every column past `content_width`.
+        let keep = !((1u128 << (MARQUEE_MAX_WIDTH - MATRIX_WIDTH - content_width)) - 1);
+        for row in canvas.rows.iter_mut() {
+            *row &= keep;
+        }
+
+        Self { rows: canvas.rows, content_width, offset: 0 }
+    }
+
+    /// Extracts the current 32px window as a [`Framebuffer`].
+    pub fn window(&self) -> Framebuffer {
+        // `advance` never lets `offset` exceed `MARQUEE_MAX_WIDTH - MATRIX_WIDTH`,
+        // so this can't underflow; `saturating_sub` is just a second line of
+        // defense against that invariant ever slipping.
+        let shift = (MARQUEE_MAX_WIDTH - MATRIX_WIDTH).saturating_sub(self.offset);
+        let mut fb = Framebuffer::new();
+        for (row, value) in fb.rows.iter_mut().enumerate() {
+            *value = ((self.rows[row] >> shift) & 0xFFFF_FFFF) as u32;
+        }
+        fb
+    }
+
+    /// Shifts the window one column to the left. Returns `false` once the
+    /// whole message has scrolled past (including trailing scroll-off), so
+    /// the caller knows when to stop.
+    pub fn advance(&mut self) -> bool {
+        let target = self.content_width + MATRIX_WIDTH;
+        if self.offset >= target {
+            return false;
+        }
+        self.offset += 1;
+        true
+    }
+}
+
+struct WideCanvas {
+    rows: [u128; MATRIX_HEIGHT as usize],
+}
+
+impl OriginDimensions for WideCanvas {
+    fn size(&self) -> Size {
+        Size::new(MARQUEE_MAX_WIDTH, MATRIX_HEIGHT)
+    }
+}
+
+impl DrawTarget for WideCanvas {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if point.x < 0 || point.x >= MARQUEE_MAX_WIDTH as i32 || point.y < 0 || point.y >= MATRIX_HEIGHT as i32 {
+                continue;
+            }
+            let bit = 1u128 << (MARQUEE_MAX_WIDTH as i32 - 1 - point.x);
+            let row = &mut self.rows[point.y as usize];
+            match color {
+                BinaryColor::On => *row |= bit,
+                BinaryColor::Off => *row &= !bit,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Regression test: a marquee longer than one matrix-width used to drive
+    // `offset` past what `window`'s shift could safely index, underflowing
+    // and panicking (or corrupting the framebuffer in release).
+    #[test]
+    fn long_marquee_scrolls_without_underflow() {
+        let mut marquee = Marquee::new("HELLO WORLD 12:34");
+        while marquee.advance() {
+            marquee.window();
+        }
+        marquee.window();
+    }
+}
+
+/// [`Display`] backend for the four chained MAX7219 (FC16) 8x8 matrices,
+/// wired up as a single 32x8 panel.
+pub struct Max7219Display<C: Connector> {
+    driver: MAX7219<C>,
+}
+
+impl<C: Connector> Max7219Display<C> {
+    /// Powers on all 4 chained devices, sets a dim intensity and blanks
+    /// them, mirroring the setup the hand-rolled `init` used to do inline.
+    pub fn new(mut driver: MAX7219<C>) -> Self {
+        driver.power_on().unwrap();
+        for dev_idx in 0..4 {
+            driver.set_intensity(dev_idx, 0x0).unwrap();
+            driver.clear_display(dev_idx).unwrap();
+        }
+        Self { driver }
+    }
+
+    /// Writes a [`Framebuffer`] straight to the panel, bypassing
+    /// [`render_clock`]. Used by the Morse-announcement marquee, which
+    /// scrolls text that isn't `ClockState` itself.
+    pub fn write_framebuffer(&mut self, fb: &Framebuffer) {
+        for (dev_idx, buffer) in fb.device_buffers().iter().enumerate() {
+            self.driver.write_raw(dev_idx, buffer).unwrap();
+        }
+    }
+}
+
+impl<C: Connector> Display for Max7219Display<C> {
+    fn render(&mut self, clock: &ClockState) {
+        let fb = render_clock(clock);
+        self.write_framebuffer(&fb);
+    }
+
+    fn clear(&mut self) {
+        for dev_idx in 0..4 {
+            self.driver.clear_display(dev_idx).unwrap();
+        }
+    }
+}
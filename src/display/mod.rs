@@ -0,0 +1,21 @@
+use crate::clock::ClockState;
+
+pub mod max7219;
+pub mod ssd1306;
+
+/// A screen that can show the current time, independent of what it's
+/// physically wired up as. `update_display` renders through this instead of
+/// poking a specific driver.
+///
+/// This only covers the once-a-second clock redraw. The Morse-announcement
+/// marquee in `cw_tick` scrolls raw framebuffers through an inherent method
+/// on `max7219::Max7219Display`, so swapping `DisplayType` for
+/// `ssd1306::Ssd1306Display` isn't the one-line change it might look like —
+/// see the comment on `DisplayType` in `main.rs`.
+pub trait Display {
+    /// Draws `clock` to the screen.
+    fn render(&mut self, clock: &ClockState);
+
+    /// Blanks the screen.
+    fn clear(&mut self);
+}
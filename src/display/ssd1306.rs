@@ -0,0 +1,76 @@
+use crate::clock::{ClockState, EditField};
+use crate::display::Display;
+use embedded_graphics::{
+    mono_font::{
+        ascii::{FONT_10X20, FONT_6X10},
+        MonoTextStyle,
+    },
+    pixelcolor::BinaryColor,
+    prelude::*,
+    text::{Baseline, Text},
+};
+use embedded_hal::blocking::i2c::Write;
+use ssd1306::{mode::BufferedGraphicsMode, prelude::*, I2CDisplayInterface, Ssd1306};
+
+/// [`Display`] backend for a 128x64 I2C SSD1306 OLED, the other display
+/// many Wokwi RP2040 builds wire up in place of an LED matrix.
+///
+/// Draws the time large (`FONT_10X20`) with a smaller status line below it.
+/// `ClockState` doesn't carry a date, so that second line shows which field
+/// the rotary encoder is currently editing instead.
+pub struct Ssd1306Display<I2C> {
+    driver: Ssd1306<I2CInterface<I2C>, DisplaySize128x64, BufferedGraphicsMode<DisplaySize128x64>>,
+}
+
+impl<I2C: Write> Ssd1306Display<I2C> {
+    pub fn new(i2c: I2C) -> Self {
+        let mut driver = Ssd1306::new(
+            I2CDisplayInterface::new(i2c),
+            DisplaySize128x64,
+            DisplayRotation::Rotate0,
+        )
+        .into_buffered_graphics_mode();
+        driver.init().unwrap();
+        Self { driver }
+    }
+}
+
+impl<I2C: Write> Display for Ssd1306Display<I2C> {
+    fn render(&mut self, clock: &ClockState) {
+        self.driver.clear(BinaryColor::Off).unwrap();
+
+        let mut time = [0u8; 8];
+        time[0] = b'0' + clock.hours / 10;
+        time[1] = b'0' + clock.hours % 10;
+        time[2] = b':';
+        time[3] = b'0' + clock.mins / 10;
+        time[4] = b'0' + clock.mins % 10;
+        time[5] = b':';
+        time[6] = b'0' + clock.secs / 10;
+        time[7] = b'0' + clock.secs % 10;
+        let time = core::str::from_utf8(&time).unwrap();
+
+        let time_style = MonoTextStyle::new(&FONT_10X20, BinaryColor::On);
+        Text::with_baseline(time, Point::new(4, 4), time_style, Baseline::Top)
+            .draw(&mut self.driver)
+            .unwrap();
+
+        let status = match clock.edit_field {
+            EditField::Run => "running",
+            EditField::Hours => "setting hours",
+            EditField::Minutes => "setting minutes",
+            EditField::Seconds => "setting seconds",
+        };
+        let status_style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+        Text::with_baseline(status, Point::new(4, 30), status_style, Baseline::Top)
+            .draw(&mut self.driver)
+            .unwrap();
+
+        self.driver.flush().unwrap();
+    }
+
+    fn clear(&mut self) {
+        self.driver.clear(BinaryColor::Off).unwrap();
+        self.driver.flush().unwrap();
+    }
+}
@@ -0,0 +1,90 @@
+/// Quadrature decode table indexed by `(prev_a << 3) | (prev_b << 2) | (a << 1) | b`.
+///
+/// Single-step transitions (`0001, 0111, 1110, 1000`) decode to clockwise
+/// (+1), their mirror images (`0010, 0100, 1101, 1011`) decode to
+/// counter-clockwise (-1), and the remaining entries are either no change
+/// (`0000, 0101, 1010, 1111`) or an invalid double transition that was
+/// likely missed by polling/IRQ latency (`0011, 1100, 0110, 1001`) — both
+/// are treated as 0 since neither tells us a direction.
+const QUADRATURE_TABLE: [i8; 16] = [
+    0, 1, -1, 0,
+    -1, 0, 0, 1,
+    1, 0, 0, -1,
+    0, -1, 1, 0,
+];
+
+/// Number of raw quadrature steps per detent (mechanical "click") of a
+/// typical EC11-style rotary encoder.
+const STEPS_PER_DETENT: i8 = 4;
+
+/// Decodes a quadrature rotary encoder into whole detent clicks.
+///
+/// Feed every A/B edge to [`Self::update`]; it accumulates raw quarter-steps
+/// and only reports a click once a full detent (4 steps) has accumulated, so
+/// callers see one `+1`/`-1` per physical click rather than per edge.
+pub struct QuadratureEncoder {
+    prev_a: bool,
+    prev_b: bool,
+    accumulator: i8,
+}
+
+impl QuadratureEncoder {
+    pub fn new(a: bool, b: bool) -> Self {
+        Self { prev_a: a, prev_b: b, accumulator: 0 }
+    }
+
+    /// Feeds a new `(a, b)` pin reading and returns the number of detents
+    /// (`-1`, `0`, or `1`) completed by this transition.
+    pub fn update(&mut self, a: bool, b: bool) -> i8 {
+        let index = ((self.prev_a as usize) << 3)
+            | ((self.prev_b as usize) << 2)
+            | ((a as usize) << 1)
+            | (b as usize);
+        self.prev_a = a;
+        self.prev_b = b;
+
+        self.accumulator += QUADRATURE_TABLE[index];
+        if self.accumulator >= STEPS_PER_DETENT {
+            self.accumulator -= STEPS_PER_DETENT;
+            1
+        } else if self.accumulator <= -STEPS_PER_DETENT {
+            self.accumulator += STEPS_PER_DETENT;
+            -1
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_clockwise_detent_reports_plus_one() {
+        let mut encoder = QuadratureEncoder::new(false, false);
+        // 00 -> 01 -> 11 -> 10 -> 00, the four quarter-steps of one detent.
+        assert_eq!(encoder.update(false, true), 0);
+        assert_eq!(encoder.update(true, true), 0);
+        assert_eq!(encoder.update(true, false), 0);
+        assert_eq!(encoder.update(false, false), 1);
+    }
+
+    #[test]
+    fn one_counter_clockwise_detent_reports_minus_one() {
+        let mut encoder = QuadratureEncoder::new(false, false);
+        // 00 -> 10 -> 11 -> 01 -> 00, the mirror image of the CW sequence.
+        assert_eq!(encoder.update(true, false), 0);
+        assert_eq!(encoder.update(true, true), 0);
+        assert_eq!(encoder.update(false, true), 0);
+        assert_eq!(encoder.update(false, false), -1);
+    }
+
+    #[test]
+    fn missed_double_transition_reports_no_change() {
+        let mut encoder = QuadratureEncoder::new(false, false);
+        // 00 -> 11 skips a quarter-step (e.g. a missed IRQ); neither
+        // direction can be inferred from it.
+        assert_eq!(encoder.update(true, true), 0);
+    }
+}